@@ -11,6 +11,11 @@ use crate::prelude::{PointStruct, Value};
 #[cfg(feature = "uuid")]
 use crate::qdrant::point_id::PointIdOptions;
 use crate::qdrant::value::Kind;
+#[cfg(feature = "serde")]
+use crate::qdrant::{
+    order_value, shard_key, vectors::VectorsOptions, NamedVectors, OrderValue, ShardKey,
+    SparseIndices, Vector,
+};
 use crate::qdrant::{
     HardwareUsage, InferenceUsage, ListValue, ModelUsage, PointId, RetrievedPoint, ScoredPoint,
     Struct, Usage, Vectors,
@@ -63,6 +68,44 @@ impl RetrievedPoint {
     pub fn try_get(&self, key: &str) -> Option<&Value> {
         self.payload.get(key)
     }
+
+    /// Deserialize the whole payload into a user-defined type.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::RetrievedPoint;
+    /// #[derive(serde::Deserialize)]
+    /// struct MyPayload {
+    ///     #[serde(default)]
+    ///     name: Option<String>,
+    /// }
+    /// let point = RetrievedPoint::default();
+    /// let payload: MyPayload = point.parse_payload().unwrap();
+    /// assert_eq!(payload.name, None);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn parse_payload<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        parse_payload(&self.payload)
+    }
+
+    /// Get a payload value using a dotted path, e.g. `"meta.tags.0"`.
+    ///
+    /// Returns the shared null value if any segment of the path is missing.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::RetrievedPoint;
+    /// let point = RetrievedPoint::default();
+    /// assert!(point.get_path("meta.tags.0").is_null());
+    /// ```
+    pub fn get_path(&self, path: &str) -> &Value {
+        match path.split_once('.') {
+            Some((key, rest)) => self.get(key).get_path(rest),
+            None => self.get(path),
+        }
+    }
 }
 
 impl ScoredPoint {
@@ -93,6 +136,55 @@ impl ScoredPoint {
     pub fn try_get(&self, key: &str) -> Option<&Value> {
         self.payload.get(key)
     }
+
+    /// Deserialize the whole payload into a user-defined type.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::ScoredPoint;
+    /// #[derive(serde::Deserialize)]
+    /// struct MyPayload {
+    ///     #[serde(default)]
+    ///     name: Option<String>,
+    /// }
+    /// let point = ScoredPoint::default();
+    /// let payload: MyPayload = point.parse_payload().unwrap();
+    /// assert_eq!(payload.name, None);
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn parse_payload<T: serde::de::DeserializeOwned>(&self) -> serde_json::Result<T> {
+        parse_payload(&self.payload)
+    }
+
+    /// Get a payload value using a dotted path, e.g. `"meta.tags.0"`.
+    ///
+    /// Returns the shared null value if any segment of the path is missing.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::ScoredPoint;
+    /// let point = ScoredPoint::default();
+    /// assert!(point.get_path("meta.tags.0").is_null());
+    /// ```
+    pub fn get_path(&self, path: &str) -> &Value {
+        match path.split_once('.') {
+            Some((key, rest)) => self.get(key).get_path(rest),
+            None => self.get(path),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+fn parse_payload<T: serde::de::DeserializeOwned>(
+    payload: &std::collections::HashMap<String, Value>,
+) -> serde_json::Result<T> {
+    let json = payload
+        .iter()
+        .map(|(k, v)| (k.clone(), v.clone().into_json()))
+        .collect();
+    serde_json::from_value(serde_json::Value::Object(json))
 }
 
 macro_rules! extract {
@@ -199,6 +291,613 @@ impl From<Value> for serde_json::Value {
     }
 }
 
+#[cfg(feature = "serde")]
+impl From<serde_json::Value> for Value {
+    /// Convert a [`serde_json::Value`] into a qdrant [`Value`].
+    ///
+    /// JSON numbers that fit in an `i64` become [`Kind::IntegerValue`],
+    /// everything else becomes [`Kind::DoubleValue`].
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use serde_json::json;
+    /// use qdrant_client::qdrant::{value::Kind::*, Value};
+    /// let value: Value = json!({"text": "Hi Qdrant!", "int": 42}).into();
+    /// assert_eq!(value.as_struct().unwrap().fields["int"].as_integer(), Some(42));
+    /// ```
+    fn from(value: serde_json::Value) -> Self {
+        let kind = match value {
+            serde_json::Value::Null => Kind::NullValue(0),
+            serde_json::Value::Bool(b) => Kind::BoolValue(b),
+            serde_json::Value::Number(n) => match n.as_i64() {
+                Some(i) => Kind::IntegerValue(i),
+                None => Kind::DoubleValue(n.as_f64().unwrap_or_default()),
+            },
+            serde_json::Value::String(s) => Kind::StringValue(s),
+            serde_json::Value::Array(values) => Kind::ListValue(ListValue {
+                values: values.into_iter().map(Value::from).collect(),
+            }),
+            serde_json::Value::Object(fields) => Kind::StructValue(Struct {
+                fields: fields.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            }),
+        };
+        Value { kind: Some(kind) }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Value> for Payload {
+    /// Convert a top-level JSON object into a [`Payload`].
+    ///
+    /// A non-object value has no keys to assign, so it becomes an empty payload.
+    fn from(value: serde_json::Value) -> Self {
+        match value {
+            serde_json::Value::Object(fields) => fields
+                .into_iter()
+                .map(|(k, v)| (k, Value::from(v)))
+                .collect::<std::collections::HashMap<_, _>>()
+                .into(),
+            _ => std::collections::HashMap::new().into(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Value {
+    /// Serialize this value as the JSON shape it represents, rather than as
+    /// the tagged `kind` union prost would otherwise produce.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.kind {
+            None | Some(Kind::NullValue(_)) => serializer.serialize_unit(),
+            Some(Kind::BoolValue(b)) => serializer.serialize_bool(*b),
+            Some(Kind::IntegerValue(i)) => serializer.serialize_i64(*i),
+            Some(Kind::DoubleValue(d)) => serializer.serialize_f64(*d),
+            Some(Kind::StringValue(s)) => serializer.serialize_str(s),
+            Some(Kind::ListValue(list)) => list.serialize(serializer),
+            Some(Kind::StructValue(s)) => s.serialize(serializer),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct ValueVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ValueVisitor {
+            type Value = Value;
+
+            fn expecting(&self, formatter: &mut Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("a qdrant payload value")
+            }
+
+            fn visit_unit<E>(self) -> Result<Self::Value, E> {
+                Ok(Value {
+                    kind: Some(Kind::NullValue(0)),
+                })
+            }
+
+            fn visit_bool<E>(self, v: bool) -> Result<Self::Value, E> {
+                Ok(Value {
+                    kind: Some(Kind::BoolValue(v)),
+                })
+            }
+
+            fn visit_i64<E>(self, v: i64) -> Result<Self::Value, E> {
+                Ok(Value {
+                    kind: Some(Kind::IntegerValue(v)),
+                })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match i64::try_from(v) {
+                    Ok(v) => self.visit_i64(v),
+                    Err(_) => self.visit_f64(v as f64),
+                }
+            }
+
+            fn visit_f64<E>(self, v: f64) -> Result<Self::Value, E> {
+                Ok(Value {
+                    kind: Some(Kind::DoubleValue(v)),
+                })
+            }
+
+            fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                self.visit_string(v.to_owned())
+            }
+
+            fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+                Ok(Value {
+                    kind: Some(Kind::StringValue(v)),
+                })
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                let mut values = Vec::new();
+                while let Some(value) = seq.next_element()? {
+                    values.push(value);
+                }
+                Ok(Value {
+                    kind: Some(Kind::ListValue(ListValue { values })),
+                })
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut fields = std::collections::HashMap::new();
+                while let Some((key, value)) = map.next_entry()? {
+                    fields.insert(key, value);
+                }
+                Ok(Value {
+                    kind: Some(Kind::StructValue(Struct { fields })),
+                })
+            }
+        }
+
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Struct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.fields.len()))?;
+        for (key, value) in &self.fields {
+            map.serialize_entry(key, value)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Struct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let fields = std::collections::HashMap::<String, Value>::deserialize(deserializer)?;
+        Ok(Struct { fields })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ListValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.values.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ListValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let values = Vec::<Value>::deserialize(deserializer)?;
+        Ok(ListValue { values })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for SparseIndices {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.data.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for SparseIndices {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let data = Vec::<u32>::deserialize(deserializer)?;
+        Ok(SparseIndices { data })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Vector {
+    /// A plain dense vector serializes as a flat array of its components.
+    /// A sparse or multivector carries extra metadata, so it serializes as
+    /// an object with `data`/`indices`/`vectors_count` fields instead.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        if self.indices.is_none() && self.vectors_count.is_none() {
+            self.data.serialize(serializer)
+        } else {
+            use serde::ser::SerializeStruct;
+            let mut s = serializer.serialize_struct("Vector", 3)?;
+            s.serialize_field("data", &self.data)?;
+            s.serialize_field("indices", &self.indices)?;
+            s.serialize_field("vectors_count", &self.vectors_count)?;
+            s.end()
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Vector {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Dense(Vec<f32>),
+            Detailed {
+                data: Vec<f32>,
+                #[serde(default)]
+                indices: Option<SparseIndices>,
+                #[serde(default)]
+                vectors_count: Option<u32>,
+            },
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Dense(data) => Vector {
+                data,
+                indices: None,
+                vectors_count: None,
+            },
+            Repr::Detailed {
+                data,
+                indices,
+                vectors_count,
+            } => Vector {
+                data,
+                indices,
+                vectors_count,
+            },
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for NamedVectors {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.vectors.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for NamedVectors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let vectors = std::collections::HashMap::<String, Vector>::deserialize(deserializer)?;
+        Ok(NamedVectors { vectors })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Vectors {
+    /// Serialize as either a flat array (a single unnamed vector) or an
+    /// object of name to array (named vectors), mirroring how Qdrant's own
+    /// REST API represents the same oneof.
+    ///
+    /// The oneof being unset (distinct from the surrounding `Option<Vectors>`
+    /// being `None`) serializes as an empty object rather than `null`, so the
+    /// two states don't collapse into the same JSON value on deserialize.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        match &self.vectors_options {
+            Some(VectorsOptions::Vector(v)) => v.serialize(serializer),
+            Some(VectorsOptions::Vectors(vs)) => vs.serialize(serializer),
+            None => serde::Serialize::serialize(
+                &std::collections::HashMap::<String, Vector>::new(),
+                serializer,
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Vectors {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Dense(Vector),
+            Named(NamedVectors),
+        }
+
+        let vectors_options = match Repr::deserialize(deserializer)? {
+            Repr::Dense(v) => Some(VectorsOptions::Vector(v)),
+            Repr::Named(vs) if vs.vectors.is_empty() => None,
+            Repr::Named(vs) => Some(VectorsOptions::Vectors(vs)),
+        };
+        Ok(Vectors { vectors_options })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ShardKey {
+    /// The oneof being unset serializes as an empty object rather than
+    /// `null`, so it doesn't collapse with the surrounding `Option<ShardKey>`
+    /// being `None` when round-tripped.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match &self.key {
+            Some(shard_key::Key::Number(n)) => serializer.serialize_u64(*n),
+            Some(shard_key::Key::Keyword(k)) => serializer.serialize_str(k),
+            None => serializer.serialize_map(Some(0))?.end(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ShardKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Number(u64),
+            Keyword(String),
+            Unset(std::collections::HashMap<String, serde::de::IgnoredAny>),
+        }
+
+        let key = match Repr::deserialize(deserializer)? {
+            Repr::Number(n) => Some(shard_key::Key::Number(n)),
+            Repr::Keyword(k) => Some(shard_key::Key::Keyword(k)),
+            Repr::Unset(_) => None,
+        };
+        Ok(ShardKey { key })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for OrderValue {
+    /// The oneof being unset serializes as an empty object rather than
+    /// `null`, so it doesn't collapse with the surrounding
+    /// `Option<OrderValue>` being `None` when round-tripped.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        match &self.variant {
+            Some(order_value::Variant::Int(i)) => serializer.serialize_i64(*i),
+            Some(order_value::Variant::Float(f)) => serializer.serialize_f64(*f),
+            None => serializer.serialize_map(Some(0))?.end(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for OrderValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Int(i64),
+            Float(f64),
+            Unset(std::collections::HashMap<String, serde::de::IgnoredAny>),
+        }
+
+        let variant = match Repr::deserialize(deserializer)? {
+            Repr::Int(i) => Some(order_value::Variant::Int(i)),
+            Repr::Float(f) => Some(order_value::Variant::Float(f)),
+            Repr::Unset(_) => None,
+        };
+        Ok(OrderValue { variant })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct PointStructReprRef<'a> {
+    id: &'a Option<PointId>,
+    vectors: &'a Option<Vectors>,
+    payload: &'a std::collections::HashMap<String, Value>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PointStructRepr {
+    id: Option<PointId>,
+    vectors: Option<Vectors>,
+    payload: std::collections::HashMap<String, Value>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PointStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        PointStructReprRef {
+            id: &self.id,
+            vectors: &self.vectors,
+            payload: &self.payload,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PointStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = PointStructRepr::deserialize(deserializer)?;
+        Ok(PointStruct {
+            id: repr.id,
+            vectors: repr.vectors,
+            payload: repr.payload,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct RetrievedPointReprRef<'a> {
+    id: &'a Option<PointId>,
+    payload: &'a std::collections::HashMap<String, Value>,
+    vectors: &'a Option<Vectors>,
+    shard_key: &'a Option<ShardKey>,
+    order_value: &'a Option<OrderValue>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RetrievedPointRepr {
+    id: Option<PointId>,
+    payload: std::collections::HashMap<String, Value>,
+    vectors: Option<Vectors>,
+    shard_key: Option<ShardKey>,
+    order_value: Option<OrderValue>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for RetrievedPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        RetrievedPointReprRef {
+            id: &self.id,
+            payload: &self.payload,
+            vectors: &self.vectors,
+            shard_key: &self.shard_key,
+            order_value: &self.order_value,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RetrievedPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = RetrievedPointRepr::deserialize(deserializer)?;
+        Ok(RetrievedPoint {
+            id: repr.id,
+            payload: repr.payload,
+            vectors: repr.vectors,
+            shard_key: repr.shard_key,
+            order_value: repr.order_value,
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize)]
+struct ScoredPointReprRef<'a> {
+    id: &'a Option<PointId>,
+    payload: &'a std::collections::HashMap<String, Value>,
+    score: f32,
+    version: u64,
+    vectors: &'a Option<Vectors>,
+    shard_key: &'a Option<ShardKey>,
+    order_value: &'a Option<OrderValue>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct ScoredPointRepr {
+    id: Option<PointId>,
+    payload: std::collections::HashMap<String, Value>,
+    score: f32,
+    version: u64,
+    vectors: Option<Vectors>,
+    shard_key: Option<ShardKey>,
+    order_value: Option<OrderValue>,
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ScoredPoint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        ScoredPointReprRef {
+            id: &self.id,
+            payload: &self.payload,
+            score: self.score,
+            version: self.version,
+            vectors: &self.vectors,
+            shard_key: &self.shard_key,
+            order_value: &self.order_value,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ScoredPoint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let repr = ScoredPointRepr::deserialize(deserializer)?;
+        Ok(ScoredPoint {
+            id: repr.id,
+            payload: repr.payload,
+            score: repr.score,
+            version: repr.version,
+            vectors: repr.vectors,
+            shard_key: repr.shard_key,
+            order_value: repr.order_value,
+        })
+    }
+}
+
 impl Display for Value {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match &self.kind {
@@ -277,6 +976,36 @@ impl Value {
             Err(NotA::default())
         }
     }
+
+    /// Get a nested value using a dotted path, e.g. `"meta.tags.0"`.
+    ///
+    /// Each segment descends through a struct field by name, or - if the
+    /// segment parses as a number - indexes into a list value. Returns the
+    /// shared null value if any segment is missing or of the wrong kind.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::Value;
+    /// let value = Value::default();
+    /// assert!(value.get_path("meta.tags.0").is_null());
+    /// ```
+    pub fn get_path(&self, path: &str) -> &Value {
+        match path.split_once('.') {
+            Some((segment, rest)) => self.get_path_segment(segment).get_path(rest),
+            None => self.get_path_segment(path),
+        }
+    }
+
+    fn get_path_segment(&self, segment: &str) -> &Value {
+        if let Ok(index) = segment.parse::<usize>() {
+            self.try_list_iter()
+                .and_then(|mut values| values.nth(index))
+                .unwrap_or(&NULL_VALUE)
+        } else {
+            self.get_value(segment).unwrap_or(&NULL_VALUE)
+        }
+    }
 }
 
 impl std::ops::Deref for ListValue {
@@ -330,6 +1059,49 @@ impl Hash for PointId {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for PointId {
+    /// Serialize as the bare numeric ID or UUID string, rather than as the
+    /// tagged `point_id_options` oneof.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use crate::qdrant::point_id::PointIdOptions;
+
+        match &self.point_id_options {
+            Some(PointIdOptions::Num(n)) => serializer.serialize_u64(*n),
+            Some(PointIdOptions::Uuid(s)) => serializer.serialize_str(s),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PointId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        use crate::qdrant::point_id::PointIdOptions;
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Num(u64),
+            Uuid(String),
+        }
+
+        let point_id_options = match Repr::deserialize(deserializer)? {
+            Repr::Num(n) => PointIdOptions::Num(n),
+            Repr::Uuid(s) => PointIdOptions::Uuid(s),
+        };
+        Ok(PointId {
+            point_id_options: Some(point_id_options),
+        })
+    }
+}
+
 impl Hash for ScoredPoint {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.id.hash(state)
@@ -358,6 +1130,26 @@ impl Usage {
             inference: InferenceUsage::aggregate_opts(self.inference, other.inference),
         }
     }
+
+    /// Fold many [`Usage`] reports, e.g. from paginated scrolls or concurrent
+    /// queries, into a single total.
+    ///
+    /// # Examples:
+    ///
+    /// ```
+    /// use qdrant_client::qdrant::Usage;
+    /// let total = Usage::aggregate_all([Usage::default(), Usage::default()]);
+    /// assert_eq!(total, Usage::default());
+    /// ```
+    pub fn aggregate_all(iter: impl IntoIterator<Item = Self>) -> Self {
+        iter.into_iter().sum()
+    }
+}
+
+impl std::iter::Sum<Usage> for Usage {
+    fn sum<I: Iterator<Item = Usage>>(iter: I) -> Self {
+        iter.fold(Usage::default(), Usage::aggregate)
+    }
 }
 
 impl HardwareUsage {
@@ -432,6 +1224,162 @@ mod tests {
 
     use super::*;
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_serde_roundtrip() {
+        let value: Value = serde_json::json!({
+            "text": "Hi Qdrant!",
+            "tags": ["a", "b"],
+            "nested": {"flag": true},
+        })
+        .into();
+
+        let json = serde_json::to_value(&value).unwrap();
+        let roundtripped: Value = serde_json::from_value(json).unwrap();
+
+        assert_eq!(value.into_json(), roundtripped.into_json());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_retrieved_point_serde_roundtrip() {
+        use crate::qdrant::point_id::PointIdOptions;
+
+        let mut payload = HashMap::new();
+        payload.insert("text".to_string(), serde_json::json!("Hi Qdrant!").into());
+
+        let point = RetrievedPoint {
+            id: Some(PointId {
+                point_id_options: Some(PointIdOptions::Num(42)),
+            }),
+            payload,
+            vectors: Some(Vectors {
+                vectors_options: Some(VectorsOptions::Vector(Vector {
+                    data: vec![0.1, 0.2, 0.3],
+                    indices: None,
+                    vectors_count: None,
+                })),
+            }),
+            shard_key: None,
+            order_value: None,
+        };
+
+        let json = serde_json::to_value(&point).unwrap();
+        let roundtripped: RetrievedPoint = serde_json::from_value(json).unwrap();
+
+        assert_eq!(point.get("text"), roundtripped.get("text"));
+        assert_eq!(point.id, roundtripped.id);
+        assert_eq!(
+            point.vectors.unwrap().vectors_options,
+            roundtripped.vectors.unwrap().vectors_options
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_sparse_vector_serde_roundtrip() {
+        let vector = Vector {
+            data: vec![0.1, 0.2],
+            indices: Some(SparseIndices { data: vec![3, 7] }),
+            vectors_count: None,
+        };
+
+        let json = serde_json::to_value(&vector).unwrap();
+        let roundtripped: Vector = serde_json::from_value(json).unwrap();
+
+        assert_eq!(vector, roundtripped);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_vectors_unset_oneof_does_not_collapse_into_none() {
+        let unset = Vectors {
+            vectors_options: None,
+        };
+
+        let json = serde_json::to_value(&unset).unwrap();
+        assert!(json.is_object());
+
+        let roundtripped: Vectors = serde_json::from_value(json).unwrap();
+        assert_eq!(unset.vectors_options, roundtripped.vectors_options);
+
+        let absent: Option<Vectors> = None;
+        assert_ne!(
+            serde_json::to_value(&unset).unwrap(),
+            serde_json::to_value(&absent).unwrap()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_scored_point_serde_roundtrip() {
+        use crate::qdrant::point_id::PointIdOptions;
+
+        let mut payload = HashMap::new();
+        payload.insert("text".to_string(), serde_json::json!("Hi Qdrant!").into());
+
+        let point = ScoredPoint {
+            id: Some(PointId {
+                point_id_options: Some(PointIdOptions::Num(42)),
+            }),
+            payload,
+            score: 0.75,
+            version: 3,
+            vectors: None,
+            shard_key: None,
+            order_value: None,
+        };
+
+        let json = serde_json::to_value(&point).unwrap();
+        let roundtripped: ScoredPoint = serde_json::from_value(json).unwrap();
+
+        assert_eq!(point.get("text"), roundtripped.get("text"));
+        assert_eq!(point.id, roundtripped.id);
+        assert_eq!(point.score, roundtripped.score);
+        assert_eq!(point.version, roundtripped.version);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_from_json_number_kind() {
+        let int_value: Value = serde_json::json!(42).into();
+        assert_eq!(int_value.as_integer(), Some(42));
+
+        let float_value: Value = serde_json::json!(4.2).into();
+        assert_eq!(float_value.as_double(), Some(4.2));
+
+        let big_value: Value = serde_json::json!(u64::MAX).into();
+        assert!(big_value.is_double());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_from_json_roundtrip() {
+        let json = serde_json::json!({"text": "Hi Qdrant!", "int": 42});
+        let value: Value = json.clone().into();
+        assert_eq!(value.into_json(), json);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_value_get_path() {
+        let value: Value = serde_json::json!({
+            "meta": {"tags": ["a", "b"]},
+        })
+        .into();
+
+        assert_eq!(
+            value.get_path("meta.tags.0").as_str().map(String::as_str),
+            Some("a")
+        );
+        assert_eq!(
+            value.get_path("meta.tags.1").as_str().map(String::as_str),
+            Some("b")
+        );
+        assert!(value.get_path("meta.tags.2").is_null());
+        assert!(value.get_path("missing.field").is_null());
+    }
+
     #[test]
     fn test_inference_usage_aggregation() {
         let mut models1 = HashMap::new();
@@ -459,4 +1407,49 @@ mod tests {
         // Check that we have exactly 3 models
         assert_eq!(aggregated.models.len(), 3);
     }
+
+    #[test]
+    fn test_usage_aggregate_all() {
+        let mut models = HashMap::new();
+        models.insert("model_a".to_string(), ModelUsage { tokens: 100 });
+
+        let usage1 = Usage {
+            hardware: Some(HardwareUsage {
+                cpu: 1,
+                payload_io_read: 0,
+                payload_io_write: 0,
+                payload_index_io_read: 0,
+                payload_index_io_write: 0,
+                vector_io_read: 0,
+                vector_io_write: 0,
+            }),
+            inference: Some(InferenceUsage { models }),
+        };
+        let usage2 = Usage {
+            hardware: Some(HardwareUsage {
+                cpu: 2,
+                payload_io_read: 0,
+                payload_io_write: 0,
+                payload_index_io_read: 0,
+                payload_index_io_write: 0,
+                vector_io_read: 0,
+                vector_io_write: 0,
+            }),
+            inference: None,
+        };
+
+        let total = Usage::aggregate_all([usage1, usage2]);
+
+        assert_eq!(total.hardware.unwrap().cpu, 3);
+        assert_eq!(
+            total
+                .inference
+                .unwrap()
+                .models
+                .get("model_a")
+                .unwrap()
+                .tokens,
+            100
+        );
+    }
 }